@@ -0,0 +1,473 @@
+use async_trait::async_trait;
+
+use crate::proto::etcdserverpb;
+use crate::proto::authpb;
+use crate::{KeyRange, Result, ResponseHeader};
+
+#[derive(Debug)]
+pub struct AuthenticateRequest {
+    proto: etcdserverpb::AuthenticateRequest,
+}
+
+impl AuthenticateRequest {
+    /// Creates a new `AuthenticateRequest` authenticating as `name` with `password`.
+    pub fn new(name: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthenticateRequest {
+                name: name.into(),
+                password: password.into(),
+            },
+        }
+    }
+}
+
+impl From<AuthenticateRequest> for etcdserverpb::AuthenticateRequest {
+    fn from(x: AuthenticateRequest) -> Self {
+        x.proto
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthenticateResponse {
+    pub header: ResponseHeader,
+    pub token: String,
+}
+
+impl From<etcdserverpb::AuthenticateResponse> for AuthenticateResponse {
+    fn from(proto: etcdserverpb::AuthenticateResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            token: proto.token,
+        }
+    }
+}
+
+/// Type of access a [`RoleGrantPermissionRequest`] grants over a key range.
+#[derive(Debug, Clone, Copy)]
+pub enum PermissionType {
+    Read,
+    Write,
+    ReadWrite,
+}
+
+impl From<PermissionType> for authpb::permission::Type {
+    fn from(value: PermissionType) -> Self {
+        match value {
+            PermissionType::Read => authpb::permission::Type::Read,
+            PermissionType::Write => authpb::permission::Type::Write,
+            PermissionType::ReadWrite => authpb::permission::Type::Readwrite,
+        }
+    }
+}
+
+macro_rules! empty_request {
+    ($name:ident, $proto:ident) => {
+        #[derive(Debug, Default)]
+        pub struct $name {
+            proto: etcdserverpb::$proto,
+        }
+
+        impl $name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+        }
+
+        impl From<$name> for etcdserverpb::$proto {
+            fn from(x: $name) -> Self {
+                x.proto
+            }
+        }
+    };
+}
+
+macro_rules! header_only_response {
+    ($name:ident, $proto:ident) => {
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            pub header: ResponseHeader,
+        }
+
+        impl From<etcdserverpb::$proto> for $name {
+            fn from(proto: etcdserverpb::$proto) -> Self {
+                Self {
+                    header: From::from(proto.header.expect("must fetch header")),
+                }
+            }
+        }
+    };
+}
+
+empty_request!(AuthEnableRequest, AuthEnableRequest);
+header_only_response!(AuthEnableResponse, AuthEnableResponse);
+
+empty_request!(AuthDisableRequest, AuthDisableRequest);
+header_only_response!(AuthDisableResponse, AuthDisableResponse);
+
+#[derive(Debug)]
+pub struct UserAddRequest {
+    proto: etcdserverpb::AuthUserAddRequest,
+}
+
+impl UserAddRequest {
+    /// Creates a new `UserAddRequest` creating a user with the given name and password.
+    pub fn new(name: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthUserAddRequest {
+                name: name.into(),
+                password: password.into(),
+                options: None,
+            },
+        }
+    }
+}
+
+impl From<UserAddRequest> for etcdserverpb::AuthUserAddRequest {
+    fn from(x: UserAddRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(UserAddResponse, AuthUserAddResponse);
+
+#[derive(Debug)]
+pub struct UserDeleteRequest {
+    proto: etcdserverpb::AuthUserDeleteRequest,
+}
+
+impl UserDeleteRequest {
+    /// Creates a new `UserDeleteRequest` removing the user named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthUserDeleteRequest { name: name.into() },
+        }
+    }
+}
+
+impl From<UserDeleteRequest> for etcdserverpb::AuthUserDeleteRequest {
+    fn from(x: UserDeleteRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(UserDeleteResponse, AuthUserDeleteResponse);
+
+empty_request!(UserListRequest, AuthUserListRequest);
+
+#[derive(Debug, Clone)]
+pub struct UserListResponse {
+    pub header: ResponseHeader,
+    pub users: Vec<String>,
+}
+
+impl From<etcdserverpb::AuthUserListResponse> for UserListResponse {
+    fn from(proto: etcdserverpb::AuthUserListResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            users: proto.users,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UserGetRequest {
+    proto: etcdserverpb::AuthUserGetRequest,
+}
+
+impl UserGetRequest {
+    /// Creates a new `UserGetRequest` fetching the roles granted to `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthUserGetRequest { name: name.into() },
+        }
+    }
+}
+
+impl From<UserGetRequest> for etcdserverpb::AuthUserGetRequest {
+    fn from(x: UserGetRequest) -> Self {
+        x.proto
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UserGetResponse {
+    pub header: ResponseHeader,
+    pub roles: Vec<String>,
+}
+
+impl From<etcdserverpb::AuthUserGetResponse> for UserGetResponse {
+    fn from(proto: etcdserverpb::AuthUserGetResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            roles: proto.roles,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UserChangePasswordRequest {
+    proto: etcdserverpb::AuthUserChangePasswordRequest,
+}
+
+impl UserChangePasswordRequest {
+    /// Creates a new `UserChangePasswordRequest` setting `name`'s password to `password`.
+    pub fn new(name: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthUserChangePasswordRequest {
+                name: name.into(),
+                password: password.into(),
+            },
+        }
+    }
+}
+
+impl From<UserChangePasswordRequest> for etcdserverpb::AuthUserChangePasswordRequest {
+    fn from(x: UserChangePasswordRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(UserChangePasswordResponse, AuthUserChangePasswordResponse);
+
+#[derive(Debug)]
+pub struct UserGrantRoleRequest {
+    proto: etcdserverpb::AuthUserGrantRoleRequest,
+}
+
+impl UserGrantRoleRequest {
+    /// Creates a new `UserGrantRoleRequest` granting `role` to `user`.
+    pub fn new(user: impl Into<String>, role: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthUserGrantRoleRequest {
+                user: user.into(),
+                role: role.into(),
+            },
+        }
+    }
+}
+
+impl From<UserGrantRoleRequest> for etcdserverpb::AuthUserGrantRoleRequest {
+    fn from(x: UserGrantRoleRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(UserGrantRoleResponse, AuthUserGrantRoleResponse);
+
+#[derive(Debug)]
+pub struct UserRevokeRoleRequest {
+    proto: etcdserverpb::AuthUserRevokeRoleRequest,
+}
+
+impl UserRevokeRoleRequest {
+    /// Creates a new `UserRevokeRoleRequest` revoking `role` from `name`.
+    pub fn new(name: impl Into<String>, role: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthUserRevokeRoleRequest {
+                name: name.into(),
+                role: role.into(),
+            },
+        }
+    }
+}
+
+impl From<UserRevokeRoleRequest> for etcdserverpb::AuthUserRevokeRoleRequest {
+    fn from(x: UserRevokeRoleRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(UserRevokeRoleResponse, AuthUserRevokeRoleResponse);
+
+#[derive(Debug)]
+pub struct RoleAddRequest {
+    proto: etcdserverpb::AuthRoleAddRequest,
+}
+
+impl RoleAddRequest {
+    /// Creates a new `RoleAddRequest` creating the role named `name`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthRoleAddRequest { name: name.into() },
+        }
+    }
+}
+
+impl From<RoleAddRequest> for etcdserverpb::AuthRoleAddRequest {
+    fn from(x: RoleAddRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(RoleAddResponse, AuthRoleAddResponse);
+
+#[derive(Debug)]
+pub struct RoleDeleteRequest {
+    proto: etcdserverpb::AuthRoleDeleteRequest,
+}
+
+impl RoleDeleteRequest {
+    /// Creates a new `RoleDeleteRequest` removing the role named `role`.
+    pub fn new(role: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthRoleDeleteRequest { role: role.into() },
+        }
+    }
+}
+
+impl From<RoleDeleteRequest> for etcdserverpb::AuthRoleDeleteRequest {
+    fn from(x: RoleDeleteRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(RoleDeleteResponse, AuthRoleDeleteResponse);
+
+empty_request!(RoleListRequest, AuthRoleListRequest);
+
+#[derive(Debug, Clone)]
+pub struct RoleListResponse {
+    pub header: ResponseHeader,
+    pub roles: Vec<String>,
+}
+
+impl From<etcdserverpb::AuthRoleListResponse> for RoleListResponse {
+    fn from(proto: etcdserverpb::AuthRoleListResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            roles: proto.roles,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RoleGetRequest {
+    proto: etcdserverpb::AuthRoleGetRequest,
+}
+
+impl RoleGetRequest {
+    /// Creates a new `RoleGetRequest` fetching the permissions granted to `role`.
+    pub fn new(role: impl Into<String>) -> Self {
+        Self {
+            proto: etcdserverpb::AuthRoleGetRequest { role: role.into() },
+        }
+    }
+}
+
+impl From<RoleGetRequest> for etcdserverpb::AuthRoleGetRequest {
+    fn from(x: RoleGetRequest) -> Self {
+        x.proto
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RoleGetResponse {
+    pub header: ResponseHeader,
+    pub perm: Vec<KeyRange>,
+}
+
+impl From<etcdserverpb::AuthRoleGetResponse> for RoleGetResponse {
+    fn from(proto: etcdserverpb::AuthRoleGetResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            perm: proto
+                .perm
+                .into_iter()
+                .map(|p| KeyRange::raw(p.key, p.range_end))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct RoleGrantPermissionRequest {
+    proto: etcdserverpb::AuthRoleGrantPermissionRequest,
+}
+
+impl RoleGrantPermissionRequest {
+    /// Creates a new `RoleGrantPermissionRequest` granting `role` `perm_type` access over
+    /// `key_range`.
+    pub fn new(role: impl Into<String>, key_range: KeyRange, perm_type: PermissionType) -> Self {
+        Self {
+            proto: etcdserverpb::AuthRoleGrantPermissionRequest {
+                name: role.into(),
+                perm: Some(authpb::Permission {
+                    perm_type: authpb::permission::Type::from(perm_type) as i32,
+                    key: key_range.key,
+                    range_end: key_range.range_end,
+                }),
+            },
+        }
+    }
+}
+
+impl From<RoleGrantPermissionRequest> for etcdserverpb::AuthRoleGrantPermissionRequest {
+    fn from(x: RoleGrantPermissionRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(RoleGrantPermissionResponse, AuthRoleGrantPermissionResponse);
+
+#[derive(Debug)]
+pub struct RoleRevokePermissionRequest {
+    proto: etcdserverpb::AuthRoleRevokePermissionRequest,
+}
+
+impl RoleRevokePermissionRequest {
+    /// Creates a new `RoleRevokePermissionRequest` revoking `role`'s access over `key_range`.
+    pub fn new(role: impl Into<String>, key_range: KeyRange) -> Self {
+        Self {
+            proto: etcdserverpb::AuthRoleRevokePermissionRequest {
+                role: role.into(),
+                key: key_range.key,
+                range_end: key_range.range_end,
+            },
+        }
+    }
+}
+
+impl From<RoleRevokePermissionRequest> for etcdserverpb::AuthRoleRevokePermissionRequest {
+    fn from(x: RoleRevokePermissionRequest) -> Self {
+        x.proto
+    }
+}
+
+header_only_response!(
+    RoleRevokePermissionResponse,
+    AuthRoleRevokePermissionResponse
+);
+
+/// Operations exposed by etcd's Auth service, covering authentication as well as RBAC
+/// administration of users, roles and permissions.
+#[async_trait]
+pub trait AuthOp {
+    async fn authenticate(&self, req: AuthenticateRequest) -> Result<AuthenticateResponse>;
+
+    async fn auth_enable(&self, req: AuthEnableRequest) -> Result<AuthEnableResponse>;
+    async fn auth_disable(&self, req: AuthDisableRequest) -> Result<AuthDisableResponse>;
+
+    async fn user_add(&self, req: UserAddRequest) -> Result<UserAddResponse>;
+    async fn user_delete(&self, req: UserDeleteRequest) -> Result<UserDeleteResponse>;
+    async fn user_list(&self, req: UserListRequest) -> Result<UserListResponse>;
+    async fn user_get(&self, req: UserGetRequest) -> Result<UserGetResponse>;
+    async fn user_change_password(
+        &self,
+        req: UserChangePasswordRequest,
+    ) -> Result<UserChangePasswordResponse>;
+    async fn user_grant_role(&self, req: UserGrantRoleRequest) -> Result<UserGrantRoleResponse>;
+    async fn user_revoke_role(&self, req: UserRevokeRoleRequest) -> Result<UserRevokeRoleResponse>;
+
+    async fn role_add(&self, req: RoleAddRequest) -> Result<RoleAddResponse>;
+    async fn role_delete(&self, req: RoleDeleteRequest) -> Result<RoleDeleteResponse>;
+    async fn role_list(&self, req: RoleListRequest) -> Result<RoleListResponse>;
+    async fn role_get(&self, req: RoleGetRequest) -> Result<RoleGetResponse>;
+    async fn role_grant_permission(
+        &self,
+        req: RoleGrantPermissionRequest,
+    ) -> Result<RoleGrantPermissionResponse>;
+    async fn role_revoke_permission(
+        &self,
+        req: RoleRevokePermissionRequest,
+    ) -> Result<RoleRevokePermissionResponse>;
+}
@@ -0,0 +1,41 @@
+use crate::proto::etcdserverpb;
+use crate::ResponseHeader;
+
+/// Request for the KV service's `Hash` RPC, which returns a hash of the local member's
+/// current KV state.
+#[derive(Debug, Default)]
+pub struct HashRequest {
+    proto: etcdserverpb::HashRequest,
+}
+
+impl HashRequest {
+    /// Creates a new `HashRequest`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<HashRequest> for etcdserverpb::HashRequest {
+    fn from(x: HashRequest) -> Self {
+        x.proto
+    }
+}
+
+/// A member's KV state hash and the revision it was computed at, as returned by `Hash`.
+///
+/// Comparing `hash` across members (discovered e.g. via [`crate::MemberListResponse`]) at
+/// matching `header.revision`s detects divergence or corruption between replicas.
+#[derive(Debug, Clone)]
+pub struct HashResponse {
+    pub header: ResponseHeader,
+    pub hash: u32,
+}
+
+impl From<etcdserverpb::HashResponse> for HashResponse {
+    fn from(proto: etcdserverpb::HashResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            hash: proto.hash,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/// A half-open `[key, range_end)` key range, as used by `Range`, `Delete` and watch creation
+/// requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyRange {
+    pub(crate) key: Vec<u8>,
+    pub(crate) range_end: Vec<u8>,
+}
+
+impl KeyRange {
+    /// Creates a `KeyRange` matching the single `key`.
+    pub fn key(key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key: key.into(),
+            range_end: Vec::new(),
+        }
+    }
+
+    /// Creates a `KeyRange` matching every key sharing `prefix`.
+    ///
+    /// This mirrors the `prefix` option of the Go and Elixir etcd clients: `range_end` is
+    /// computed by incrementing the last byte of `prefix` that is less than `0xff` and
+    /// truncating everything after it. A prefix that is empty or made up entirely of `0xff`
+    /// bytes has no such byte, so it maps to the `range_end` `[0x00]`, meaning "all keys".
+    pub fn prefix(prefix: impl Into<Vec<u8>>) -> Self {
+        let key = prefix.into();
+        let range_end = prefix_range_end(&key);
+        Self { key, range_end }
+    }
+
+    /// Creates a `KeyRange` matching every key greater than or equal to `key`.
+    pub fn from_key(key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            key: key.into(),
+            range_end: vec![0],
+        }
+    }
+
+    /// Creates a `KeyRange` matching every key in the keyspace.
+    pub fn all() -> Self {
+        Self {
+            key: vec![0],
+            range_end: vec![0],
+        }
+    }
+
+    /// Creates a `KeyRange` from an already-computed `key`/`range_end` pair, e.g. one
+    /// reported back by the server.
+    pub(crate) fn raw(key: Vec<u8>, range_end: Vec<u8>) -> Self {
+        Self { key, range_end }
+    }
+}
+
+fn prefix_range_end(prefix: &[u8]) -> Vec<u8> {
+    let mut range_end = prefix.to_vec();
+    for i in (0..range_end.len()).rev() {
+        if range_end[i] < 0xff {
+            range_end[i] += 1;
+            range_end.truncate(i + 1);
+            return range_end;
+        }
+    }
+    // `prefix` is empty or entirely 0xff bytes: there is no successor, so match all keys.
+    vec![0]
+}
+
+impl From<&str> for KeyRange {
+    fn from(key: &str) -> Self {
+        KeyRange::key(key.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for KeyRange {
+    fn from(key: String) -> Self {
+        KeyRange::key(key.into_bytes())
+    }
+}
+
+impl From<Vec<u8>> for KeyRange {
+    fn from(key: Vec<u8>) -> Self {
+        KeyRange::key(key)
+    }
+}
@@ -0,0 +1,20 @@
+mod hash;
+mod key_range;
+mod range;
+
+pub use hash::{HashRequest, HashResponse};
+pub use key_range::KeyRange;
+pub use range::{RangeRequest, RangeResponse, RangeStream, SortOrder};
+
+use async_trait::async_trait;
+
+use crate::Result;
+
+/// Operations exposed by etcd's KV service.
+#[async_trait]
+pub trait KeyValueOp {
+    async fn range(&self, req: RangeRequest) -> Result<RangeResponse>;
+
+    /// Returns a hash of this member's local KV state, for cross-member consistency checks.
+    async fn hash(&self, req: HashRequest) -> Result<HashResponse>;
+}
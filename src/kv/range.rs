@@ -1,4 +1,6 @@
-use super::{KeyRange, KeyValue};
+use std::collections::VecDeque;
+
+use super::{KeyRange, KeyValue, KeyValueOp};
 use crate::proto::etcdserverpb;
 use crate::ResponseHeader;
 
@@ -45,6 +47,14 @@ impl RangeRequest {
         self
     }
 
+    /// Option for specifying that the request can be served by any member of the cluster,
+    /// not only the leader. This trades linearizability for lower latency, so the response
+    /// may reflect a slightly stale view of the keyspace.
+    pub fn serializable(mut self) -> Self {
+        self.proto.serializable = true;
+        self
+    }
+
     /// Option for specifying that the response will contain keys only, without values.
     pub fn keys_only(mut self) -> Self {
         self.proto.keys_only = true;
@@ -115,6 +125,20 @@ impl RangeRequest {
         self.proto.sort_order = order.into();
         self
     }
+
+    /// Turns this request into a [`RangeStream`] that transparently pages through the whole
+    /// key range, fetching `page_size` keys at a time instead of returning everything in one
+    /// response. The range is forced to sort ascending by key so consecutive pages can resume
+    /// from the last key seen.
+    pub fn paginate(mut self, page_size: u64) -> RangeStream {
+        self = self.sort_by_key(SortOrder::Ascending).limit(page_size);
+        RangeStream {
+            proto: self.proto,
+            buf: VecDeque::new(),
+            exhausted: false,
+            revision_pinned: false,
+        }
+    }
 }
 
 impl<T> From<T> for RangeRequest
@@ -172,3 +196,53 @@ impl From<etcdserverpb::RangeResponse> for RangeResponse {
         }
     }
 }
+
+/// An auto-paginating scan over a key range, built by [`RangeRequest::paginate`].
+///
+/// `RangeStream` reissues the underlying `Range` RPC one page at a time, resuming each page
+/// from the successor of the last key returned so no key is served twice. Once the first page
+/// comes back, its header revision is pinned for all subsequent pages so the whole scan
+/// observes a single consistent snapshot even if the keyspace is being written concurrently.
+#[derive(Debug)]
+pub struct RangeStream {
+    proto: etcdserverpb::RangeRequest,
+    buf: VecDeque<KeyValue>,
+    exhausted: bool,
+    revision_pinned: bool,
+}
+
+impl RangeStream {
+    /// Fetches the next key in the range, issuing a new page request when the current page has
+    /// been drained. Returns `Ok(None)` once the whole range has been exhausted.
+    pub async fn next<C: KeyValueOp>(&mut self, client: &C) -> crate::Result<Option<KeyValue>> {
+        if let Some(kv) = self.buf.pop_front() {
+            return Ok(Some(kv));
+        }
+
+        if self.exhausted {
+            return Ok(None);
+        }
+
+        let resp: RangeResponse = client.range(RangeRequest { proto: self.proto.clone() }).await?;
+
+        if !self.revision_pinned {
+            // A revision set by the caller already pins the snapshot; the header revision is
+            // the store's current one, not the revision the page was read at.
+            if self.proto.revision <= 0 {
+                self.proto.revision = resp.header.revision;
+            }
+            self.revision_pinned = true;
+        }
+
+        if let Some(last) = resp.kvs.last() {
+            let mut next_key = last.key.clone();
+            next_key.push(0x00);
+            self.proto.key = next_key;
+        }
+
+        self.exhausted = !resp.has_more;
+        self.buf.extend(resp.kvs);
+
+        Ok(self.buf.pop_front())
+    }
+}
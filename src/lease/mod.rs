@@ -0,0 +1,369 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::{oneshot, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::proto::etcdserverpb;
+use crate::{Result, ResponseHeader};
+
+/// Identifier of a lease, as returned by [`LeaseGrantResponse::id`].
+pub type LeaseId = i64;
+
+#[derive(Debug)]
+pub struct LeaseGrantRequest {
+    proto: etcdserverpb::LeaseGrantRequest,
+}
+
+impl LeaseGrantRequest {
+    /// Creates a new `LeaseGrantRequest` asking the cluster for a lease with the given TTL,
+    /// in seconds.
+    pub fn new(ttl: i64) -> Self {
+        Self {
+            proto: etcdserverpb::LeaseGrantRequest { ttl, id: 0 },
+        }
+    }
+}
+
+impl From<LeaseGrantRequest> for etcdserverpb::LeaseGrantRequest {
+    fn from(x: LeaseGrantRequest) -> Self {
+        x.proto
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaseGrantResponse {
+    pub header: ResponseHeader,
+    pub id: LeaseId,
+    pub ttl: i64,
+}
+
+impl From<etcdserverpb::LeaseGrantResponse> for LeaseGrantResponse {
+    fn from(proto: etcdserverpb::LeaseGrantResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            id: proto.id,
+            ttl: proto.ttl,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LeaseRevokeRequest {
+    proto: etcdserverpb::LeaseRevokeRequest,
+}
+
+impl LeaseRevokeRequest {
+    /// Creates a new `LeaseRevokeRequest` for the given lease.
+    pub fn new(id: LeaseId) -> Self {
+        Self {
+            proto: etcdserverpb::LeaseRevokeRequest { id },
+        }
+    }
+}
+
+impl From<LeaseRevokeRequest> for etcdserverpb::LeaseRevokeRequest {
+    fn from(x: LeaseRevokeRequest) -> Self {
+        x.proto
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaseRevokeResponse {
+    pub header: ResponseHeader,
+}
+
+impl From<etcdserverpb::LeaseRevokeResponse> for LeaseRevokeResponse {
+    fn from(proto: etcdserverpb::LeaseRevokeResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct LeaseKeepAliveRequest {
+    proto: etcdserverpb::LeaseKeepAliveRequest,
+}
+
+impl LeaseKeepAliveRequest {
+    /// Creates a new `LeaseKeepAliveRequest` refreshing the given lease.
+    pub fn new(id: LeaseId) -> Self {
+        Self {
+            proto: etcdserverpb::LeaseKeepAliveRequest { id },
+        }
+    }
+}
+
+impl From<LeaseKeepAliveRequest> for etcdserverpb::LeaseKeepAliveRequest {
+    fn from(x: LeaseKeepAliveRequest) -> Self {
+        x.proto
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaseKeepAliveResponse {
+    pub header: ResponseHeader,
+    pub id: LeaseId,
+    pub ttl: i64,
+}
+
+impl From<etcdserverpb::LeaseKeepAliveResponse> for LeaseKeepAliveResponse {
+    fn from(proto: etcdserverpb::LeaseKeepAliveResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            id: proto.id,
+            ttl: proto.ttl,
+        }
+    }
+}
+
+/// Identifies the lease kept alive over the `LeaseKeepAlive` RPC stream. It holds only the
+/// lease id; it has no methods for sending keepalive pings or reading the server's responses.
+#[derive(Debug)]
+pub struct LeaseKeepAlive {
+    id: LeaseId,
+}
+
+impl LeaseKeepAlive {
+    pub(crate) fn new(id: LeaseId) -> Self {
+        Self { id }
+    }
+
+    /// Id of the lease this stream is keeping alive.
+    pub fn id(&self) -> LeaseId {
+        self.id
+    }
+}
+
+#[derive(Debug)]
+pub struct LeaseTimeToLiveRequest {
+    proto: etcdserverpb::LeaseTimeToLiveRequest,
+}
+
+impl LeaseTimeToLiveRequest {
+    /// Creates a new `LeaseTimeToLiveRequest` for the given lease.
+    pub fn new(id: LeaseId) -> Self {
+        Self {
+            proto: etcdserverpb::LeaseTimeToLiveRequest { id, keys: false },
+        }
+    }
+
+    /// Additionally requests the list of keys attached to the lease.
+    pub fn with_keys(mut self) -> Self {
+        self.proto.keys = true;
+        self
+    }
+}
+
+impl From<LeaseTimeToLiveRequest> for etcdserverpb::LeaseTimeToLiveRequest {
+    fn from(x: LeaseTimeToLiveRequest) -> Self {
+        x.proto
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaseTimeToLiveResponse {
+    pub header: ResponseHeader,
+    pub id: LeaseId,
+    pub ttl: i64,
+    pub granted_ttl: i64,
+    pub keys: Vec<Vec<u8>>,
+}
+
+impl From<etcdserverpb::LeaseTimeToLiveResponse> for LeaseTimeToLiveResponse {
+    fn from(proto: etcdserverpb::LeaseTimeToLiveResponse) -> Self {
+        Self {
+            header: From::from(proto.header.expect("must fetch header")),
+            id: proto.id,
+            ttl: proto.ttl,
+            granted_ttl: proto.granted_ttl,
+            keys: proto.keys,
+        }
+    }
+}
+
+/// Operations exposed by etcd's Lease service.
+#[async_trait]
+pub trait LeaseOp {
+    async fn lease_grant(&self, req: LeaseGrantRequest) -> Result<LeaseGrantResponse>;
+    async fn lease_revoke(&self, req: LeaseRevokeRequest) -> Result<LeaseRevokeResponse>;
+    async fn lease_keep_alive(&self, req: LeaseKeepAliveRequest) -> Result<LeaseKeepAliveResponse>;
+    async fn lease_time_to_live(&self, req: LeaseTimeToLiveRequest)
+        -> Result<LeaseTimeToLiveResponse>;
+}
+
+/// Callback invoked after a successful background keepalive refresh, with the TTL reported
+/// by the server for the renewed lease.
+type KeepAliveCallback = Box<dyn Fn(Duration) + Send + Sync>;
+/// Callback invoked once the lease's background refresh loop has stopped, either because
+/// `cancel()` was called or because a keepalive failed and the lease was lost.
+type CancelCallback = Box<dyn Fn() + Send + Sync>;
+
+struct Callbacks {
+    on_keepalive: Option<KeepAliveCallback>,
+    on_cancel: Option<CancelCallback>,
+}
+
+/// A stateful handle onto a granted lease that keeps it alive in the background, modeled on
+/// etcd3-py's `Lease`. Construct with [`Lease::new`], then hold onto it for as long as the
+/// lease should stay alive; dropping or calling [`Lease::cancel`] stops the refresh loop.
+pub struct Lease {
+    id: LeaseId,
+    client: Arc<dyn LeaseOp + Send + Sync>,
+    ttl: Arc<Mutex<Option<Duration>>>,
+    alive: Arc<AtomicBool>,
+    callbacks: Arc<Mutex<Callbacks>>,
+    cancel_tx: Option<oneshot::Sender<()>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl Lease {
+    /// Grants a new lease with the given TTL and immediately starts refreshing it in the
+    /// background at roughly `ttl / 3` intervals.
+    pub async fn new<C>(client: C, ttl: Duration) -> Result<Self>
+    where
+        C: LeaseOp + Send + Sync + 'static,
+    {
+        let resp = client
+            .lease_grant(LeaseGrantRequest::new(ttl.as_secs() as i64))
+            .await?;
+        Ok(Self::spawn(client, resp.id, Duration::from_secs(resp.ttl.max(1) as u64)))
+    }
+
+    /// Wraps an already-granted lease, starting the background refresh loop for it.
+    pub fn spawn<C>(client: C, id: LeaseId, ttl: Duration) -> Self
+    where
+        C: LeaseOp + Send + Sync + 'static,
+    {
+        let client: Arc<dyn LeaseOp + Send + Sync> = Arc::new(client);
+        let ttl_cell = Arc::new(Mutex::new(Some(ttl)));
+        let alive = Arc::new(AtomicBool::new(true));
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
+        let callbacks = Arc::new(Mutex::new(Callbacks {
+            on_keepalive: None,
+            on_cancel: None,
+        }));
+
+        let task = {
+            let client = client.clone();
+            let ttl_cell = ttl_cell.clone();
+            let alive = alive.clone();
+            let callbacks = callbacks.clone();
+            tokio::spawn(async move {
+                loop {
+                    let interval = ttl_cell
+                        .lock()
+                        .await
+                        .map(|ttl| ttl / 3)
+                        .unwrap_or(Duration::from_secs(1));
+
+                    tokio::select! {
+                        _ = &mut cancel_rx => break,
+                        _ = tokio::time::sleep(interval) => {}
+                    }
+
+                    match client.lease_keep_alive(LeaseKeepAliveRequest::new(id)).await {
+                        Ok(resp) if resp.ttl > 0 => {
+                            let new_ttl = Duration::from_secs(resp.ttl as u64);
+                            *ttl_cell.lock().await = Some(new_ttl);
+                            if let Some(cb) = &callbacks.lock().await.on_keepalive {
+                                cb(new_ttl);
+                            }
+                        }
+                        // A non-positive TTL means the lease is already gone server-side
+                        // (expired or revoked), same as a hard RPC failure below.
+                        Ok(_) | Err(_) => {
+                            alive.store(false, Ordering::SeqCst);
+                            *ttl_cell.lock().await = None;
+                            break;
+                        }
+                    }
+                }
+
+                alive.store(false, Ordering::SeqCst);
+                if let Some(cb) = &callbacks.lock().await.on_cancel {
+                    cb();
+                }
+            })
+        };
+
+        Self {
+            id,
+            client,
+            ttl: ttl_cell,
+            alive,
+            callbacks,
+            cancel_tx: Some(cancel_tx),
+            task: Some(task),
+        }
+    }
+
+    /// Issues a single keepalive immediately, independent of the background refresh loop's
+    /// schedule, updating the tracked TTL with the server's response.
+    ///
+    /// A non-positive TTL in the response means the lease is already gone server-side; this
+    /// is reported the same way the background loop would treat it, marking the lease dead.
+    pub async fn refresh(&self) -> Result<Duration> {
+        let resp = self
+            .client
+            .lease_keep_alive(LeaseKeepAliveRequest::new(self.id))
+            .await?;
+        if resp.ttl <= 0 {
+            self.alive.store(false, Ordering::SeqCst);
+            *self.ttl.lock().await = None;
+            return Ok(Duration::ZERO);
+        }
+        let new_ttl = Duration::from_secs(resp.ttl as u64);
+        *self.ttl.lock().await = Some(new_ttl);
+        Ok(new_ttl)
+    }
+
+    /// Registers a callback invoked after each successful background keepalive, with the
+    /// TTL the server reported for the renewed lease. Replaces any previously registered
+    /// `on_keepalive` callback.
+    pub async fn on_keepalive(&self, callback: impl Fn(Duration) + Send + Sync + 'static) {
+        self.callbacks.lock().await.on_keepalive = Some(Box::new(callback));
+    }
+
+    /// Registers a callback invoked once the background refresh loop stops, whether due to
+    /// [`Lease::cancel`] or a failed keepalive. Replaces any previously registered
+    /// `on_cancel` callback.
+    pub async fn on_cancel(&self, callback: impl Fn() + Send + Sync + 'static) {
+        self.callbacks.lock().await.on_cancel = Some(Box::new(callback));
+    }
+
+    /// Id of the underlying lease.
+    pub fn id(&self) -> LeaseId {
+        self.id
+    }
+
+    /// Whether the background refresh loop is still believed to be keeping this lease alive.
+    /// Returns `false` once a keepalive has failed or the lease has been cancelled.
+    pub fn alive(&self) -> bool {
+        self.alive.load(Ordering::SeqCst)
+    }
+
+    /// The TTL reported by the most recent keepalive (the granted TTL until the first one),
+    /// or `None` if the lease has been lost.
+    pub async fn ttl(&self) -> Option<Duration> {
+        *self.ttl.lock().await
+    }
+
+    /// Stops the background refresh loop without revoking the lease on the server; the lease
+    /// will expire naturally once its TTL elapses.
+    pub fn cancel(&mut self) {
+        self.alive.store(false, Ordering::SeqCst);
+        if let Some(tx) = self.cancel_tx.take() {
+            let _ = tx.send(());
+        }
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    Client, KeyValue, KeyValueOp, LeaseGrantRequest, LeaseId, LeaseOp, LeaseRevokeRequest,
+    PutRequest, RangeRequest, Result, WatchCreateRequest, WatchInbound, WatchOp,
+};
+
+/// Delay after revoking a cached key's leasing key before the write that invalidated it is
+/// allowed to complete, giving the revocation time to propagate so no read racing with the
+/// write can observe stale cached data.
+const REVOKE_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Default TTL granted to a leasing key backing a cached entry.
+const LEASING_KEY_TTL_SECS: i64 = 60;
+
+#[derive(Debug, Clone)]
+struct CachedEntry {
+    kvs: Vec<KeyValue>,
+    mod_revision: i64,
+    lease_id: LeaseId,
+}
+
+type Cache = Arc<RwLock<HashMap<String, CachedEntry>>>;
+
+/// Per-key locks serializing the "check cache, talk to the cluster, update cache" sequences
+/// in `get` and `put`, so two concurrent callers touching the same key don't each grant their
+/// own lease, or race a revoke against an already-revoked lease.
+type KeyLocks = Arc<Mutex<HashMap<String, Arc<Mutex<()>>>>>;
+
+/// A write-through, lease-backed local cache in front of a [`Client`], modeled on etcd's
+/// `clientv3/leasing`. A `get` for any key acquires a leasing key, stored under `prefix` and
+/// backed by a lease, and serves subsequent reads for that key out of an in-memory cache, without
+/// round-tripping to the cluster, until a write invalidates it or the leasing key's lease is
+/// revoked or expires.
+#[derive(Debug)]
+pub struct LeasingClient {
+    client: Client,
+    prefix: String,
+    cache: Cache,
+    key_locks: KeyLocks,
+}
+
+impl Client {
+    /// Wraps this client with a [`LeasingClient`] caching reads, storing its leasing keys
+    /// under `prefix` (the leasing key for `key` is `prefix` followed by `key`).
+    ///
+    /// This is opt-in: only callers that go through the returned `LeasingClient` benefit
+    /// from caching, the underlying `Client` keeps behaving exactly as before.
+    pub fn with_leasing(&self, prefix: impl Into<String>) -> LeasingClient {
+        LeasingClient {
+            client: self.clone(),
+            prefix: prefix.into(),
+            cache: Arc::new(RwLock::new(HashMap::new())),
+            key_locks: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl LeasingClient {
+    /// Name of the leasing key tracking the cache entry for `key`.
+    fn leasing_key(&self, key: &str) -> String {
+        format!("{}{}", self.prefix, key)
+    }
+
+    /// Returns the lock serializing cache/cluster operations for `key`, creating it if this
+    /// is the first caller to touch the key.
+    async fn key_lock(&self, key: &str) -> Arc<Mutex<()>> {
+        self.key_locks
+            .lock()
+            .await
+            .entry(key.to_owned())
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone()
+    }
+
+    /// Reads `key`, serving it from the local cache when a cached entry is present and
+    /// otherwise fetching it from the cluster, acquiring a leasing key and caching the
+    /// result so later reads avoid the round trip.
+    pub async fn get(&self, key: impl Into<String>) -> Result<Vec<KeyValue>> {
+        let key = key.into();
+
+        if let Some(entry) = self.cache.read().await.get(&key) {
+            return Ok(entry.kvs.clone());
+        }
+
+        // Serialize the whole "miss, grant a lease, cache" sequence per key: without this,
+        // concurrent first-time `get`s for the same key would each grant their own lease and
+        // watch, and only the last insert would survive in the map, leaking the rest until
+        // their TTL expires.
+        let lock = self.key_lock(&key).await;
+        let _guard = lock.lock().await;
+
+        if let Some(entry) = self.cache.read().await.get(&key) {
+            return Ok(entry.kvs.clone());
+        }
+
+        let resp = self.client.range(RangeRequest::from(key.clone())).await?;
+
+        let lease = self
+            .client
+            .lease_grant(LeaseGrantRequest::new(LEASING_KEY_TTL_SECS))
+            .await?;
+
+        // Attach the leasing key to the lease so that the lease expiring or being revoked
+        // (see `put` below) deletes it, which the watch spawned below observes as an
+        // invalidation.
+        let leasing_key = self.leasing_key(&key);
+        self.client
+            .put(PutRequest::new(leasing_key.clone(), Vec::new()).lease(lease.id))
+            .await?;
+
+        let stream = self
+            .client
+            .watch(WatchCreateRequest::from(leasing_key))
+            .await?;
+        tokio::spawn(evict_on_invalidation(self.cache.clone(), key.clone(), stream));
+
+        self.cache.write().await.insert(
+            key,
+            CachedEntry {
+                kvs: resp.kvs.clone(),
+                mod_revision: resp.header.revision,
+                lease_id: lease.id,
+            },
+        );
+
+        Ok(resp.kvs)
+    }
+
+    /// Writes `key`, revoking its leasing key (if any) and waiting out a short backoff so no
+    /// read racing with this write can be served from the now-stale cache entry, then issues
+    /// the write and evicts the local entry.
+    pub async fn put(&self, key: impl Into<String>, value: impl Into<Vec<u8>>) -> Result<()> {
+        let key = key.into();
+
+        // Hold the per-key lock across the whole revoke-then-write sequence so a second
+        // concurrent `put` for the same key only runs after this one has already removed
+        // the cache entry, instead of also reading the now-stale `lease_id` and trying (and
+        // failing) to revoke an already-revoked lease.
+        let lock = self.key_lock(&key).await;
+        let _guard = lock.lock().await;
+
+        let lease_id = self.cache.read().await.get(&key).map(|entry| entry.lease_id);
+        if let Some(lease_id) = lease_id {
+            self.client
+                .lease_revoke(LeaseRevokeRequest::new(lease_id))
+                .await?;
+            tokio::time::sleep(REVOKE_BACKOFF).await;
+            self.cache.write().await.remove(&key);
+        }
+
+        self.client.put(PutRequest::new(key.clone(), value)).await?;
+        self.cache.write().await.remove(&key);
+
+        Ok(())
+    }
+}
+
+/// Consumes a leasing key's watch stream, evicting `key` from `cache` as soon as the leasing
+/// key is deleted (lease expiry or revocation) or the stream itself closes.
+async fn evict_on_invalidation(cache: Cache, key: String, mut stream: crate::WatchStream) {
+    // The leasing key is only ever written once (see `get`) and never updated again, so any
+    // inbound message here is the delete generated by the lease expiring or being revoked;
+    // treat a closed stream the same way, since it means we can no longer observe the lease.
+    let _: Option<WatchInbound> = stream.inbound().await;
+    cache.write().await.remove(&key);
+}
@@ -15,21 +15,31 @@
 //!
 //! etcd-rs supports etcd v3 API and async/await syntax.
 
-pub use auth::{AuthOp, AuthenticateRequest, AuthenticateResponse};
+pub use auth::{
+    AuthDisableRequest, AuthDisableResponse, AuthEnableRequest, AuthEnableResponse, AuthOp,
+    AuthenticateRequest, AuthenticateResponse, PermissionType, RoleAddRequest, RoleAddResponse,
+    RoleDeleteRequest, RoleDeleteResponse, RoleGetRequest, RoleGetResponse,
+    RoleGrantPermissionRequest, RoleGrantPermissionResponse, RoleListRequest, RoleListResponse,
+    RoleRevokePermissionRequest, RoleRevokePermissionResponse, UserAddRequest, UserAddResponse,
+    UserChangePasswordRequest, UserChangePasswordResponse, UserDeleteRequest, UserDeleteResponse,
+    UserGetRequest, UserGetResponse, UserGrantRoleRequest, UserGrantRoleResponse,
+    UserListRequest, UserListResponse, UserRevokeRoleRequest, UserRevokeRoleResponse,
+};
 pub use cluster::{
     ClusterOp, Member, MemberAddRequest, MemberAddResponse, MemberListRequest, MemberListResponse,
     MemberRemoveRequest, MemberRemoveResponse, MemberUpdateRequest, MemberUpdateResponse,
 };
 pub use kv::{
-    CompactRequest, CompactResponse, DeleteRequest, DeleteResponse, KeyRange, KeyValue, KeyValueOp,
-    PutRequest, PutResponse, RangeRequest, RangeResponse, SortOrder, TxnCmp, TxnOp, TxnOpResponse,
-    TxnRequest, TxnResponse,
+    CompactRequest, CompactResponse, DeleteRequest, DeleteResponse, HashRequest, HashResponse,
+    KeyRange, KeyValue, KeyValueOp, PutRequest, PutResponse, RangeRequest, RangeResponse,
+    RangeStream, SortOrder, TxnCmp, TxnOp, TxnOpResponse, TxnRequest, TxnResponse,
 };
 pub use lease::{
-    LeaseGrantRequest, LeaseGrantResponse, LeaseId, LeaseKeepAlive, LeaseKeepAliveRequest,
+    Lease, LeaseGrantRequest, LeaseGrantResponse, LeaseId, LeaseKeepAlive, LeaseKeepAliveRequest,
     LeaseKeepAliveResponse, LeaseOp, LeaseRevokeRequest, LeaseRevokeResponse,
     LeaseTimeToLiveRequest, LeaseTimeToLiveResponse,
 };
+pub use leasing::LeasingClient;
 pub use response_header::ResponseHeader;
 pub use watch::{
     Event, EventType, WatchCancelRequest, WatchCanceler, WatchCreateRequest, WatchInbound, WatchOp,
@@ -45,6 +55,7 @@ mod cluster;
 mod error;
 mod kv;
 mod lease;
+mod leasing;
 mod lock;
 mod proto;
 mod response_header;